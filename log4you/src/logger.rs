@@ -1,5 +1,6 @@
-use std::path::PathBuf;
-use std::process;
+use std::cell::RefCell;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
 use log4rs;
 use once_cell::sync::Lazy;
@@ -11,6 +12,44 @@ use crate::utils::log_id::LogIdFormat;
 /// Global target log name (default: "log4you")
 pub static LOG_TARGET: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new("log4you".to_string()));
 
+/// Programmatic logging configuration.
+///
+/// Holds the settings that `log4you` needs to wire up a backend without an on-disk
+/// YAML file, so the crate can be configured from code by libraries, tests, or
+/// daemons. It is consumed by [`Logger::init_syslog`] and is cheap to clone;
+/// [`LoggerBuilder`] does not take a `LogConfig` and is configured through its
+/// own setters.
+#[derive(Debug, Clone, Default)]
+pub struct LogConfig {
+    /// Name of the service or module, used as the log target and — for the syslog
+    /// backend — as the `openlog` ident/tag. Falls back to the global [`LOG_TARGET`]
+    /// default (`"log4you"`) when `None`.
+    pub service_name: Option<String>,
+    /// A `RUST_LOG`-style level filter directive (e.g. `"info,db=debug"`). Only the
+    /// root level is honoured by the syslog backend; per-target entries are ignored
+    /// there ([`LoggerBuilder::level_filter`] is the path that applies them).
+    pub level_filter: Option<String>,
+}
+
+thread_local! {
+    /// The `log_id` currently active on this thread, if any.
+    ///
+    /// When set, every auto macro (`log_info!`, `log_warn!`, ...) reuses this id
+    /// instead of minting a fresh one, so that all lines emitted during the same
+    /// request share a single correlation key.
+    static CURRENT_LOG_ID: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "async-context")]
+tokio::task_local! {
+    /// The `log_id` bound to the current async task, if any.
+    ///
+    /// Used when the `async-context` feature is enabled so that a `log_id` set
+    /// via [`Logger::scope_log_id`] survives across `.await` points and task
+    /// boundaries, where a plain `thread_local!` would not.
+    static TASK_LOG_ID: String;
+}
+
 /// A struct responsible for initializing and managing the logger for the application.
 ///
 /// The `Logger` struct handles the configuration of the logging system, allowing you to
@@ -91,13 +130,10 @@ impl Logger {
     ///
     /// # Notes
     ///
-    /// - Ensure the `log4you.yaml` file exists and is valid, otherwise the program will exit (`exit(1)`).
+    /// - If the `log4you.yaml` file is missing or invalid, the logger falls back to a programmatic
+    ///   console logger (see [`Logger::builder`]) instead of exiting the process.
     /// - Use macros like `log_info!`, `log_error!`, etc., from this crate to maintain consistent log format.
     ///
-    /// # Panics
-    ///
-    /// This function will terminate the program if the configuration file is not found or is invalid.
-    ///
     /// # Example log4rs YAML configuration for the `log4you` crate.
     ///
     /// This configuration defines two appenders: one for logging to the console and
@@ -160,29 +196,645 @@ impl Logger {
 
         let config_path = config_path.map(PathBuf::from).unwrap_or_else(|| PathBuf::from("../config/log4you.yaml"));
 
-        if !config_path.exists() {
+        if config_path.exists() {
+            match log4rs::init_file(&config_path, Default::default()) {
+                Ok(_) => {
+                    log::info!("log_id={}, Logger initialized from {}", log_id, config_path.display());
+                    let _ = Uuid::from_log_id(log_id);
+                    return;
+                }
+                Err(e) => {
+                    eprintln!(
+                        "log_id={}, Logger init error: {}. Falling back to console logger.",
+                        log_id, e
+                    );
+                }
+            }
+        } else {
             eprintln!(
-                "log_id={}, Warning: Config file {} not found. Exiting.",
+                "log_id={}, Warning: Config file {} not found. Falling back to console logger.",
                 log_id,
                 config_path.display()
             );
-            process::exit(1);
         }
 
-        match log4rs::init_file(&config_path, Default::default()) {
-            Ok(_) => {
-                log::info!("log_id={}, Logger initialized from {}", log_id, config_path.display());
+        // No usable YAML config: initialize a console logger programmatically rather
+        // than tearing down the whole process, so libraries and tests can recover.
+        match Logger::builder().console(true).build() {
+            Ok(()) => {
+                log::info!("log_id={}, Logger initialized with console fallback", log_id);
                 let _ = Uuid::from_log_id(log_id);
             }
             Err(e) => {
-                eprintln!("log_id={}, Logger init error: {}. Exiting.", log_id, e);
-                process::exit(1);
+                eprintln!("log_id={}, Logger init error: {}", log_id, e);
             }
         }
     }
 
+    /// Starts building a logger configuration programmatically.
+    ///
+    /// This is the fallible, never-exiting counterpart to [`Logger::init`]: it assembles a
+    /// `log4rs::Config` in code — console and/or rolling-file appenders with the crate's
+    /// standard encoder pattern — so no on-disk YAML file is required, and reports problems
+    /// through [`InitError`] instead of calling [`std::process::exit`]. `init` does not go
+    /// through the builder when its YAML file loads: it calls `log4rs::init_file` directly and
+    /// uses `Logger::builder().console(true).build()` only when the file is missing or invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use log4you::logger::Logger;
+    ///
+    /// Logger::builder()
+    ///     .service_name("my_service")
+    ///     .level_filter("info,db=debug")
+    ///     .console(true)
+    ///     .rolling_file("logs/my_service.log", "100MB", 5)
+    ///     .build()
+    ///     .expect("failed to initialize logger");
+    /// ```
+    pub fn builder() -> LoggerBuilder {
+        LoggerBuilder::default()
+    }
+
     /// Getter for target global
     pub fn target() -> String {
         LOG_TARGET.read().unwrap().clone()
     }
+
+    /// Initializes logging against the local system logger (POSIX syslog).
+    ///
+    /// For daemons on Unix, file and console appenders are rarely the right sink —
+    /// records should flow to syslog with a proper severity mapping. This installs a
+    /// [`log::Log`] implementation that writes through the POSIX `openlog`/`syslog`/
+    /// `closelog` API, mapping `Error → LOG_ERR`, `Warn → LOG_WARNING`,
+    /// `Info → LOG_INFO` and `Debug`/`Trace → LOG_DEBUG`, and using the configured
+    /// `service_name` as the syslog ident/tag. Each line is formatted into a reusable
+    /// thread-local buffer to avoid a per-message allocation, keeping the familiar
+    /// `log_id=...` prefix.
+    ///
+    /// Only available on Unix with the `syslog` cargo feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`log::SetLoggerError`] if a global logger has already been installed.
+    #[cfg(all(unix, feature = "syslog"))]
+    pub fn init_syslog(config: LogConfig) -> Result<(), log::SetLoggerError> {
+        if let Some(name) = config.service_name.as_deref() {
+            let mut target = LOG_TARGET.write().unwrap();
+            *target = name.to_string();
+        }
+
+        let ident = config
+            .service_name
+            .clone()
+            .unwrap_or_else(|| LOG_TARGET.read().unwrap().clone());
+
+        let max_level = config
+            .level_filter
+            .as_deref()
+            .map(|s| LevelDirectives::parse(s).default_level())
+            .unwrap_or(log::LevelFilter::Info);
+
+        let logger = syslog_backend::SyslogLogger::new(ident, max_level);
+        log::set_boxed_logger(Box::new(logger))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+
+    /// Binds a `log_id` to the current thread for the rest of its execution.
+    ///
+    /// Once set, the auto macros (`log_info!`, `log_warn!`, ...) reuse this id
+    /// instead of generating a new one on every call, turning `log_id` into a
+    /// true correlation key across a call chain. Call [`Logger::clear_log_id`]
+    /// to drop it again (for example when a request finishes and the thread is
+    /// returned to a pool).
+    ///
+    /// Prefer [`Logger::with_log_id`] when the scope is lexical, as it restores
+    /// the previous id automatically.
+    pub fn set_log_id(log_id: &str) {
+        CURRENT_LOG_ID.with(|slot| {
+            *slot.borrow_mut() = Some(log_id.to_string());
+        });
+    }
+
+    /// Clears any `log_id` bound to the current thread.
+    ///
+    /// After this call the auto macros fall back to generating a fresh id per
+    /// call again, until a new one is installed with [`Logger::set_log_id`] or
+    /// [`Logger::with_log_id`].
+    pub fn clear_log_id() {
+        CURRENT_LOG_ID.with(|slot| {
+            *slot.borrow_mut() = None;
+        });
+    }
+
+    /// Runs `f` with `log_id` bound as the current correlation id, restoring the
+    /// previously active id (if any) when it returns.
+    ///
+    /// This is the recommended entry point for request handlers: wrap the whole
+    /// unit of work so every log line emitted inside — directly or from helpers —
+    /// carries the same id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use log4you::{logger::Logger, log_id, log_info};
+    ///
+    /// let request_id = log_id!();
+    /// Logger::with_log_id(&request_id, || {
+    ///     log_info!("handling request");
+    ///     log_info!("still the same log_id");
+    /// });
+    /// ```
+    pub fn with_log_id<F, R>(log_id: &str, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        // Restore via an RAII guard so the previous id is put back even if `f`
+        // panics; otherwise a caught panic on a pooled thread would leave the
+        // stale scoped id in place and mis-attribute later requests.
+        struct Restore(Option<String>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_LOG_ID.with(|slot| {
+                    *slot.borrow_mut() = self.0.take();
+                });
+            }
+        }
+
+        let _guard = Restore(CURRENT_LOG_ID.with(|slot| {
+            slot.borrow_mut().replace(log_id.to_string())
+        }));
+        f()
+    }
+
+    /// Runs the future `fut` with `log_id` bound as the current correlation id for
+    /// the whole async task, so it is preserved across `.await` points.
+    ///
+    /// Only available when the `async-context` feature is enabled, which pulls in
+    /// `tokio`'s task-local support.
+    #[cfg(feature = "async-context")]
+    pub async fn scope_log_id<F, R>(log_id: &str, fut: F) -> R
+    where
+        F: std::future::Future<Output = R>,
+    {
+        TASK_LOG_ID.scope(log_id.to_string(), fut).await
+    }
+
+    /// Returns the `log_id` currently in effect, or `None` when none is bound.
+    ///
+    /// When the `async-context` feature is enabled the task-local id (set via
+    /// [`Logger::scope_log_id`]) takes precedence over the thread-local one, so a
+    /// task keeps its id even after being polled on a different worker thread.
+    pub fn current_log_id() -> Option<String> {
+        #[cfg(feature = "async-context")]
+        {
+            if let Ok(id) = TASK_LOG_ID.try_with(|id| id.clone()) {
+                return Some(id);
+            }
+        }
+        CURRENT_LOG_ID.with(|slot| slot.borrow().clone())
+    }
+}
+
+/// The encoder pattern shared by every programmatically-built appender.
+///
+/// Matches the pattern documented for the YAML configuration, so code-built and
+/// file-built loggers produce identical lines.
+const DEFAULT_PATTERN: &str = "[{d(%Y-%m-%dT%H:%M:%S%.6f)} {h({l})} {f}:{L}] - {m}{n}";
+
+/// An error raised while building or installing a logger configuration.
+///
+/// Returned by [`LoggerBuilder::build`] in place of the process-terminating behaviour of
+/// the original YAML-only path, so callers can decide how to recover.
+#[derive(Debug)]
+pub enum InitError {
+    /// A size limit string (e.g. `"100MB"`) could not be parsed.
+    InvalidSize(String),
+    /// Constructing an appender failed (for example, the log directory is not writable).
+    Appender(std::io::Error),
+    /// `log4rs` rejected the assembled configuration.
+    Config(String),
+    /// A global logger had already been installed.
+    SetLogger(log::SetLoggerError),
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitError::InvalidSize(s) => write!(f, "invalid size limit: {}", s),
+            InitError::Appender(e) => write!(f, "failed to build appender: {}", e),
+            InitError::Config(e) => write!(f, "invalid logger configuration: {}", e),
+            InitError::SetLogger(e) => write!(f, "logger already initialized: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for InitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InitError::Appender(e) => Some(e),
+            InitError::SetLogger(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for InitError {
+    fn from(e: std::io::Error) -> Self {
+        InitError::Appender(e)
+    }
+}
+
+impl From<log::SetLoggerError> for InitError {
+    fn from(e: log::SetLoggerError) -> Self {
+        InitError::SetLogger(e)
+    }
+}
+
+/// Rolling-file appender settings captured by the builder.
+struct RollingFileSpec {
+    path: PathBuf,
+    size_limit: String,
+    count: u32,
+}
+
+/// A fluent builder that assembles a `log4rs` configuration in code.
+///
+/// Obtained via [`Logger::builder`]. Every setter returns `self` so calls can be chained,
+/// and [`LoggerBuilder::build`] installs the resulting configuration, returning an
+/// [`InitError`] rather than exiting on failure. If neither a console nor a rolling-file
+/// appender is requested, a console appender is enabled by default so the logger is never
+/// left without a sink.
+#[derive(Default)]
+pub struct LoggerBuilder {
+    service_name: Option<String>,
+    level_filter: Option<String>,
+    console: bool,
+    rolling_file: Option<RollingFileSpec>,
+}
+
+impl LoggerBuilder {
+    /// Sets the service name, used as the log target (see [`LOG_TARGET`]).
+    pub fn service_name(mut self, name: impl Into<String>) -> Self {
+        self.service_name = Some(name.into());
+        self
+    }
+
+    /// Sets a `RUST_LOG`-style level filter directive (e.g. `"info,db=debug"`).
+    ///
+    /// The root level is taken from the directive's default (the bare level, or `info` when
+    /// absent); per-target directives are honoured at runtime by the level filter.
+    pub fn level_filter(mut self, directive: impl Into<String>) -> Self {
+        self.level_filter = Some(directive.into());
+        self
+    }
+
+    /// Enables or disables the console (`stdout`) appender.
+    pub fn console(mut self, enabled: bool) -> Self {
+        self.console = enabled;
+        self
+    }
+
+    /// Adds a rolling-file appender with a compound size trigger and fixed-window roller.
+    ///
+    /// - `path`: the active log file path.
+    /// - `size_limit`: per-file size trigger, e.g. `"100MB"` (suffixes `B`/`KB`/`MB`/`GB`/`TB`).
+    /// - `count`: number of rolled backups to keep.
+    pub fn rolling_file(mut self, path: impl Into<PathBuf>, size_limit: impl Into<String>, count: u32) -> Self {
+        self.rolling_file = Some(RollingFileSpec {
+            path: path.into(),
+            size_limit: size_limit.into(),
+            count,
+        });
+        self
+    }
+
+    /// Builds the configuration and installs it as the global logger.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InitError`] if a size limit is malformed, an appender cannot be built,
+    /// the configuration is rejected by `log4rs`, or a logger is already installed.
+    pub fn build(self) -> Result<(), InitError> {
+        use log4rs::append::console::ConsoleAppender;
+        use log4rs::append::rolling_file::policy::compound::roll::fixed_window::FixedWindowRoller;
+        use log4rs::append::rolling_file::policy::compound::trigger::size::SizeTrigger;
+        use log4rs::append::rolling_file::policy::compound::CompoundPolicy;
+        use log4rs::append::rolling_file::RollingFileAppender;
+        use log4rs::config::{Appender, Config, Root};
+        use log4rs::encode::pattern::PatternEncoder;
+
+        if let Some(name) = self.service_name.as_deref() {
+            let mut target = LOG_TARGET.write().unwrap();
+            *target = name.to_string();
+        }
+
+        // Default to a console sink when nothing else was requested.
+        let console = self.console || self.rolling_file.is_none();
+
+        let mut config = Config::builder();
+        let mut root = Root::builder();
+
+        if console {
+            let appender = ConsoleAppender::builder()
+                .encoder(Box::new(PatternEncoder::new(DEFAULT_PATTERN)))
+                .build();
+            config = config.appender(Appender::builder().build("stdout", Box::new(appender)));
+            root = root.appender("stdout");
+        }
+
+        if let Some(spec) = self.rolling_file {
+            let limit = parse_size(&spec.size_limit)?;
+            let pattern = rolled_pattern(&spec.path);
+            let roller = FixedWindowRoller::builder()
+                .build(&pattern, spec.count)
+                .map_err(|e| InitError::Config(e.to_string()))?;
+            let policy = CompoundPolicy::new(Box::new(SizeTrigger::new(limit)), Box::new(roller));
+            let appender = RollingFileAppender::builder()
+                .encoder(Box::new(PatternEncoder::new(DEFAULT_PATTERN)))
+                .build(&spec.path, Box::new(policy))?;
+            config = config.appender(Appender::builder().build("rolling", Box::new(appender)));
+            root = root.appender("rolling");
+        }
+
+        // A `RUST_LOG`-style directive from the environment overrides the builder's
+        // `level_filter` at runtime, so operators can retune per-target levels without
+        // editing and redeploying a YAML file. Per-target entries become log4rs loggers
+        // that stay additive, composing with the appenders configured above.
+        let directive = std::env::var(ENV_FILTER).ok().or_else(|| self.level_filter.clone());
+        let directives = directive.as_deref().map(LevelDirectives::parse).unwrap_or_default();
+
+        for (target, level) in directives.targets() {
+            config = config.logger(log4rs::config::Logger::builder().build(target.clone(), *level));
+        }
+
+        let config = config
+            .build(root.build(directives.default_level()))
+            .map_err(|e| InitError::Config(e.to_string()))?;
+
+        log4rs::init_config(config)?;
+        Ok(())
+    }
+}
+
+/// Derives the fixed-window roller pattern from the active log file path.
+///
+/// `logs/app.log` becomes `logs/app-{}.log`, matching the YAML example.
+fn rolled_pattern(path: &Path) -> String {
+    let parent = path.parent();
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("log4you");
+    let ext = path.extension().and_then(|s| s.to_str());
+    let name = match ext {
+        Some(ext) => format!("{}-{{}}.{}", stem, ext),
+        None => format!("{}-{{}}", stem),
+    };
+    match parent {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.join(name).to_string_lossy().into_owned(),
+        _ => name,
+    }
+}
+
+/// The environment variable consulted for a `RUST_LOG`-style level override.
+pub const ENV_FILTER: &str = "LOG4YOU";
+
+/// A parsed set of `RUST_LOG`-style level directives.
+///
+/// A directive string such as `"info,myservice=debug,myservice::db=trace"` is split on commas:
+/// a bare level (e.g. `info` or `off`) sets the default/root level, while a `target=level`
+/// entry overrides the level for a module-path prefix. Lookups pick the *longest* matching
+/// prefix, mirroring the per-module control documented for the `log`/`env_logger` ecosystem.
+#[derive(Debug, Clone)]
+pub struct LevelDirectives {
+    default: log::LevelFilter,
+    /// `(target prefix, level)` entries, kept sorted by descending prefix length so the
+    /// first match found is also the longest.
+    targets: Vec<(String, log::LevelFilter)>,
+}
+
+impl Default for LevelDirectives {
+    fn default() -> Self {
+        LevelDirectives {
+            default: log::LevelFilter::Info,
+            targets: Vec::new(),
+        }
+    }
+}
+
+impl LevelDirectives {
+    /// Parses a comma-separated directive string.
+    ///
+    /// Empty and unparseable entries are ignored; when several bare levels are present the
+    /// last one wins, matching `env_logger`'s precedence.
+    pub fn parse(directive: &str) -> Self {
+        let mut result = LevelDirectives::default();
+        for part in directive.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            match part.split_once('=') {
+                Some((target, level)) => {
+                    let target = target.trim();
+                    if let Ok(level) = level.trim().parse::<log::LevelFilter>() {
+                        if !target.is_empty() {
+                            result.targets.push((target.to_string(), level));
+                        }
+                    }
+                }
+                None => {
+                    if let Ok(level) = part.parse::<log::LevelFilter>() {
+                        result.default = level;
+                    }
+                }
+            }
+        }
+        result
+            .targets
+            .sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        result
+    }
+
+    /// The default (root) level applied to targets with no matching directive.
+    pub fn default_level(&self) -> log::LevelFilter {
+        self.default
+    }
+
+    /// The per-target directives, ordered longest prefix first.
+    pub fn targets(&self) -> &[(String, log::LevelFilter)] {
+        &self.targets
+    }
+
+    /// Resolves the level for `target`, picking the longest matching module-path prefix and
+    /// falling back to the default level when none matches.
+    pub fn level_for(&self, target: &str) -> log::LevelFilter {
+        self.targets
+            .iter()
+            .find(|(prefix, _)| {
+                target == prefix
+                    || target
+                        .strip_prefix(prefix.as_str())
+                        .map(|rest| rest.starts_with("::"))
+                        .unwrap_or(false)
+            })
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+}
+
+/// Parses a human-readable size string (e.g. `"100MB"`) into a byte count.
+///
+/// Accepts an optional `B`/`KB`/`MB`/`GB`/`TB` suffix (case-insensitive, 1024-based); a
+/// bare number is treated as bytes.
+fn parse_size(input: &str) -> Result<u64, InitError> {
+    let trimmed = input.trim();
+    let split = trimmed
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let value: u64 = number
+        .trim()
+        .parse()
+        .map_err(|_| InitError::InvalidSize(input.to_string()))?;
+    let multiplier = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" => 1024,
+        "MB" => 1024 * 1024,
+        "GB" => 1024 * 1024 * 1024,
+        "TB" => 1024u64 * 1024 * 1024 * 1024,
+        _ => return Err(InitError::InvalidSize(input.to_string())),
+    };
+    value
+        .checked_mul(multiplier)
+        .ok_or_else(|| InitError::InvalidSize(input.to_string()))
+}
+
+/// A `log::Log` backend that writes through the POSIX syslog API.
+///
+/// Mirrors the design of slog-syslog's `SyslogDrain` and crosvm's `syslog` module:
+/// the ident string handed to `openlog` is kept alive for the lifetime of the
+/// logger, and each record is rendered into a thread-local buffer before being
+/// forwarded to `syslog(3)`.
+#[cfg(all(unix, feature = "syslog"))]
+mod syslog_backend {
+    use std::cell::RefCell;
+    use std::ffi::CString;
+    use std::fmt::Write;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    thread_local! {
+        /// Scratch buffer reused across calls on a thread so formatting a line
+        /// does not allocate on every message.
+        static LINE: RefCell<String> = const { RefCell::new(String::new()) };
+    }
+
+    /// Installs into the POSIX syslog facility and forwards every enabled record.
+    pub(super) struct SyslogLogger {
+        /// Kept alive because `openlog` stores the pointer rather than copying it.
+        _ident: CString,
+        max_level: LevelFilter,
+    }
+
+    impl SyslogLogger {
+        /// Opens the syslog connection with the given ident and returns the logger.
+        pub(super) fn new(ident: String, max_level: LevelFilter) -> Self {
+            let ident = CString::new(ident).unwrap_or_else(|_| CString::new("log4you").unwrap());
+            // SAFETY: `ident` outlives the logger (stored below) and therefore the
+            // pointer handed to `openlog`, which keeps it for the process lifetime.
+            unsafe {
+                libc::openlog(
+                    ident.as_ptr(),
+                    libc::LOG_PID | libc::LOG_CONS,
+                    libc::LOG_USER,
+                );
+            }
+            SyslogLogger {
+                _ident: ident,
+                max_level,
+            }
+        }
+    }
+
+    impl Log for SyslogLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= self.max_level
+        }
+
+        fn log(&self, record: &Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+
+            let priority = match record.level() {
+                Level::Error => libc::LOG_ERR,
+                Level::Warn => libc::LOG_WARNING,
+                Level::Info => libc::LOG_INFO,
+                Level::Debug | Level::Trace => libc::LOG_DEBUG,
+            };
+
+            LINE.with(|buf| {
+                let mut buf = buf.borrow_mut();
+                buf.clear();
+
+                // `record.args()` already begins with the `log_id=...` prefix (kept by the
+                // macro for `{m}`-style sinks), so it is the single source of truth here —
+                // re-reading the `log_id` key/value would duplicate it.
+                let _ = write!(buf, "{}", record.args());
+                // NUL-terminate so the buffer can be passed to the "%s" format below.
+                buf.push('\0');
+
+                // SAFETY: `buf` is a valid, NUL-terminated C string for the duration
+                // of this call and the format string is a constant "%s".
+                unsafe {
+                    libc::syslog(
+                        priority,
+                        b"%s\0".as_ptr() as *const libc::c_char,
+                        buf.as_ptr() as *const libc::c_char,
+                    );
+                }
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    impl Drop for SyslogLogger {
+        fn drop(&mut self) {
+            // SAFETY: pairs with the `openlog` call in `new`.
+            unsafe {
+                libc::closelog();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_size_handles_suffixes_and_bare_bytes() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("1B").unwrap(), 1);
+        assert_eq!(parse_size("100MB").unwrap(), 100 * 1024 * 1024);
+        assert_eq!(parse_size(" 2 gb ").unwrap(), 2 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn parse_size_rejects_overflow_and_garbage() {
+        assert!(matches!(parse_size("18446744073709551615TB"), Err(InitError::InvalidSize(_))));
+        assert!(matches!(parse_size("10PB"), Err(InitError::InvalidSize(_))));
+        assert!(matches!(parse_size("abc"), Err(InitError::InvalidSize(_))));
+    }
+
+    #[test]
+    fn level_for_picks_longest_prefix_and_avoids_false_positives() {
+        let d = LevelDirectives::parse("info,myservice=debug,myservice::db=trace");
+        assert_eq!(d.default_level(), log::LevelFilter::Info);
+        assert_eq!(d.level_for("myservice"), log::LevelFilter::Debug);
+        assert_eq!(d.level_for("myservice::db"), log::LevelFilter::Trace);
+        assert_eq!(d.level_for("myservice::db::pool"), log::LevelFilter::Trace);
+        // A target that only shares a textual prefix must not match the directive.
+        assert_eq!(d.level_for("myservicex"), log::LevelFilter::Info);
+        // An unrelated target falls back to the root level.
+        assert_eq!(d.level_for("other"), log::LevelFilter::Info);
+    }
 }
@@ -1,84 +1,160 @@
-/// Logs an info-level message with a dynamically generated `log_id`.
+/// Internal helper that emits a single log record, splitting optional structured
+/// key/value fields from the message at the `;` separator.
+///
+/// This is not part of the public API — it backs the `log_*!` macros. The token
+/// muncher walks the argument list one token at a time, accumulating everything
+/// before the first bare `;` as structured fields and treating the remainder as
+/// the `format_args!`-style message. When no `;` is present the whole argument
+/// list is the message and no user fields are attached. In every case the
+/// resolved `log_id` and the current `service` name are emitted as first-class
+/// key/value pairs on the `Record` (via the `log` crate's kv API) so that a
+/// JSON-capable appender can serialize them alongside any caller-supplied fields.
+/// The `log_id` is *also* kept as a `log_id=...` prefix in the rendered message so
+/// the pattern-encoder `{m}` sinks (console, rolling file) still carry it, since
+/// `PatternEncoder` does not render key/values.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log4you_emit {
+    // Found the `;` that separates structured fields from the message.
+    ($level:ident, $log_id:expr, [$($field:tt)*] ; $($msg:tt)*) => {{
+        let log_id = $log_id;
+        // Normalise to `&str`: `log`'s base `kv` feature only implements `ToValue`
+        // for `&str`, not `String`, and callers pass either (the auto macros bind a
+        // `String`, the `_with_id` variants a `&str` literal).
+        let log_id: &str = log_id.as_ref();
+        let target = $crate::logger::Logger::target();
+        log::$level!(
+            target: &*target,
+            log_id = log_id,
+            service = target.as_str(),
+            $($field)* ;
+            "log_id={}, {}", log_id, format_args!($($msg)*)
+        );
+    }};
+
+    // No `;` yet: munch one more token into the field accumulator.
+    ($level:ident, $log_id:expr, [$($field:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__log4you_emit!($level, $log_id, [$($field)* $next] $($rest)*)
+    };
+
+    // Reached the end without a `;`: the accumulated tokens are the message and
+    // there are no caller-supplied structured fields.
+    ($level:ident, $log_id:expr, [$($msg:tt)*]) => {{
+        let log_id = $log_id;
+        let log_id: &str = log_id.as_ref();
+        let target = $crate::logger::Logger::target();
+        log::$level!(
+            target: &*target,
+            log_id = log_id,
+            service = target.as_str() ;
+            "log_id={}, {}", log_id, format_args!($($msg)*)
+        );
+    }};
+}
+
+/// Logs an info-level message, reusing the request-scoped `log_id` when one is set.
 ///
-/// This macro automatically generates a `log_id` using the `log_id!` macro and uses the `Logger::target`
-/// to determine the log target, which can be customized for the service. It then formats the message using
-/// `log::info!` with the generated `log_id` and the provided message arguments.
+/// The macro first consults `Logger::current_log_id`, so every line emitted inside a
+/// `Logger::with_log_id` scope shares the same correlation id; only when no context id is
+/// active does it fall back to generating a fresh one via the `log_id!` macro. Structured
+/// fields may be supplied before the message using `log`'s key/value syntax, separated from
+/// the message by a `;`; together with the built-in `log_id` and `service` pairs they are
+/// attached to the record rather than interpolated into the string.
 ///
 /// # Example:
 /// ```rust
 /// use log4you::log_info;
 /// log_info!("User logged in successfully");
+/// log_info!(user_id = 42; "User logged in successfully");
 /// ```
 #[macro_export]
 macro_rules! log_info {
-    ($($arg:tt)*) => {{
-        let log_id = $crate::log_id!();
-        let target = $crate::logger::Logger::target();
-        log::info!(target: &*target, "log_id={}, {}", log_id, format_args!($($arg)*));
-    }}
+    ($($arg:tt)*) => {
+        $crate::__log4you_emit!(
+            info,
+            $crate::logger::Logger::current_log_id().unwrap_or_else(|| $crate::log_id!()),
+            [] $($arg)*
+        )
+    };
 }
 
-/// Logs a warning-level message with a dynamically generated `log_id`.
+/// Logs a warning-level message, reusing the request-scoped `log_id` when one is set.
 ///
-/// This macro generates a `log_id` using the `log_id!` macro and utilizes the `Logger::target`
-/// to log the warning message with the `log_id` and the provided message arguments.
+/// The active context id from `Logger::current_log_id` is used if present, otherwise a fresh
+/// `log_id` is generated with the `log_id!` macro. Optional structured fields may precede the
+/// message, separated by a `;`, and are emitted as record key/value pairs alongside the
+/// built-in `log_id` and `service`.
 ///
 /// # Example:
 /// ```rust
 /// use log4you::log_warn;
 /// log_warn!("Failed to load configuration");
+/// log_warn!(retries = 3; "Failed to load configuration");
 /// ```
 #[macro_export]
 macro_rules! log_warn {
-    ($($arg:tt)*) => {{
-        let log_id = $crate::log_id!();
-        let target = $crate::logger::Logger::target();
-        log::warn!(target: &*target, "log_id={}, {}", log_id, format_args!($($arg)*));
-    }}
+    ($($arg:tt)*) => {
+        $crate::__log4you_emit!(
+            warn,
+            $crate::logger::Logger::current_log_id().unwrap_or_else(|| $crate::log_id!()),
+            [] $($arg)*
+        )
+    };
 }
 
-/// Logs an error-level message with a dynamically generated `log_id`.
+/// Logs an error-level message, reusing the request-scoped `log_id` when one is set.
 ///
-/// This macro generates a `log_id` using the `log_id!` macro and utilizes the `Logger::target`
-/// to log the error message with the `log_id` and the provided message arguments.
+/// When a context id is active (see `Logger::with_log_id`) it is reused via
+/// `Logger::current_log_id`; otherwise the `log_id!` macro mints one. Structured fields given
+/// before a `;` are attached to the record together with the built-in `log_id` and `service`
+/// key/value pairs.
 ///
 /// # Example:
 /// ```rust
 /// use log4you::log_error;
 /// log_error!("An unexpected error occurred");
+/// log_error!(code = 500; "An unexpected error occurred");
 /// ```
 #[macro_export]
 macro_rules! log_error {
-    ($($arg:tt)*) => {{
-        let log_id = $crate::log_id!();
-        let target = $crate::logger::Logger::target();
-        log::error!(target: &*target, "log_id={}, {}", log_id, format_args!($($arg)*));
-    }}
+    ($($arg:tt)*) => {
+        $crate::__log4you_emit!(
+            error,
+            $crate::logger::Logger::current_log_id().unwrap_or_else(|| $crate::log_id!()),
+            [] $($arg)*
+        )
+    };
 }
 
-/// Logs a debug-level message with a dynamically generated `log_id`.
+/// Logs a debug-level message, reusing the request-scoped `log_id` when one is set.
 ///
-/// This macro generates a `log_id` using the `log_id!` macro and utilizes the `Logger::target`
-/// to log the debug message with the `log_id` and the provided message arguments.
+/// Like the other auto macros it prefers the context id from `Logger::current_log_id` and only
+/// falls back to `log_id!` when none is bound. Structured fields may precede the message,
+/// separated by a `;`, and are emitted as record key/value pairs alongside the built-in
+/// `log_id` and `service`.
 ///
 /// # Example:
 /// ```rust
 /// use log4you::log_debug;
 /// log_debug!("Debugging user session data");
+/// log_debug!(session = "abc"; "Debugging user session data");
 /// ```
 #[macro_export]
 macro_rules! log_debug {
-    ($($arg:tt)*) => {{
-        let log_id = $crate::log_id!();
-        let target = $crate::logger::Logger::target();
-        log::debug!(target: &*target, "log_id={}, {}", log_id, format_args!($($arg)*));
-    }}
+    ($($arg:tt)*) => {
+        $crate::__log4you_emit!(
+            debug,
+            $crate::logger::Logger::current_log_id().unwrap_or_else(|| $crate::log_id!()),
+            [] $($arg)*
+        )
+    };
 }
 
 /// Logs an info-level message with a custom `log_id` provided as a parameter.
 ///
 /// This macro allows specifying a custom `log_id` for logging messages at the info level, along
-/// with the message arguments. The `Logger::target` is used to log the message to the appropriate service.
+/// with the message arguments. Structured fields may be supplied before the message, separated
+/// by a `;`, and are emitted as record key/value pairs together with the `log_id` and `service`.
 ///
 /// # Example:
 /// ```rust
@@ -89,15 +165,15 @@ macro_rules! log_debug {
 #[macro_export]
 macro_rules! log_info_with_id {
     ($log_id:expr, $($arg:tt)*) => {
-        let target = $crate::logger::Logger::target();
-        log::info!(target: &*target, "log_id={}, {}", $log_id, format_args!($($arg)*));
+        $crate::__log4you_emit!(info, $log_id, [] $($arg)*)
     };
 }
 
 /// Logs a warning-level message with a custom `log_id` provided as a parameter.
 ///
 /// This macro allows specifying a custom `log_id` for logging messages at the warning level, along
-/// with the message arguments. The `Logger::target` is used to log the message to the appropriate service.
+/// with the message arguments. Structured fields may precede the message, separated by a `;`, and
+/// are emitted as record key/value pairs together with the `log_id` and `service`.
 ///
 /// # Example:
 /// ```rust
@@ -107,15 +183,15 @@ macro_rules! log_info_with_id {
 #[macro_export]
 macro_rules! log_warn_with_id {
     ($log_id:expr, $($arg:tt)*) => {
-        let target = $crate::logger::Logger::target();
-        log::warn!(target: &*target, "log_id={}, {}", $log_id, format_args!($($arg)*));
+        $crate::__log4you_emit!(warn, $log_id, [] $($arg)*)
     };
 }
 
 /// Logs an error-level message with a custom `log_id` provided as a parameter.
 ///
 /// This macro allows specifying a custom `log_id` for logging messages at the error level, along
-/// with the message arguments. The `Logger::target` is used to log the message to the appropriate service.
+/// with the message arguments. Structured fields may precede the message, separated by a `;`, and
+/// are emitted as record key/value pairs together with the `log_id` and `service`.
 ///
 /// # Example:
 /// ```rust
@@ -125,15 +201,15 @@ macro_rules! log_warn_with_id {
 #[macro_export]
 macro_rules! log_error_with_id {
     ($log_id:expr, $($arg:tt)*) => {
-        let target = $crate::logger::Logger::target();
-        log::error!(target: &*target, "log_id={}, {}", $log_id, format_args!($($arg)*));
+        $crate::__log4you_emit!(error, $log_id, [] $($arg)*)
     };
 }
 
 /// Logs a debug-level message with a custom `log_id` provided as a parameter.
 ///
 /// This macro allows specifying a custom `log_id` for logging messages at the debug level, along
-/// with the message arguments. The `Logger::target` is used to log the message to the appropriate service.
+/// with the message arguments. Structured fields may precede the message, separated by a `;`, and
+/// are emitted as record key/value pairs together with the `log_id` and `service`.
 ///
 /// # Example:
 /// ```rust
@@ -143,8 +219,7 @@ macro_rules! log_error_with_id {
 #[macro_export]
 macro_rules! log_debug_with_id {
     ($log_id:expr, $($arg:tt)*) => {
-        let target = $crate::logger::Logger::target();
-        log::debug!(target: &*target, "log_id={}, {}", $log_id, format_args!($($arg)*));
+        $crate::__log4you_emit!(debug, $log_id, [] $($arg)*)
     };
 }
 
@@ -1,3 +1,5 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use uuid::Uuid;
 
 /// Trait for conversion between UUID and log_id format (32 char without '-')
@@ -5,6 +7,15 @@ use uuid::Uuid;
 pub trait LogIdFormat {
     fn to_log_id(&self) -> String;
     fn from_log_id(log_id: &str) -> Option<Uuid>;
+
+    /// Recovers the `SystemTime` encoded in a UUIDv7-based log_id.
+    ///
+    /// Because `log_id!()` mints its ids with `Uuid::now_v7`, the first 48 bits carry the
+    /// millisecond Unix timestamp of creation. This validates and re-hyphenates the input
+    /// exactly like [`from_log_id`](LogIdFormat::from_log_id), rejects any UUID whose version
+    /// is not 7, and otherwise returns the embedded instant — letting operators order and
+    /// bucket entries by id alone. Returns `None` for malformed or non-v7 input.
+    fn log_id_timestamp(log_id: &str) -> Option<SystemTime>;
 }
 
 impl LogIdFormat for Uuid {
@@ -28,4 +39,48 @@ impl LogIdFormat for Uuid {
 
         Uuid::parse_str(&formatted).ok()
     }
+
+    fn log_id_timestamp(log_id: &str) -> Option<SystemTime> {
+        let uuid = Self::from_log_id(log_id)?;
+        if uuid.get_version_num() != 7 {
+            return None;
+        }
+
+        // The high 48 bits of a v7 UUID are the big-endian milliseconds since the epoch.
+        let bytes = uuid.as_bytes();
+        let mut millis: u64 = 0;
+        for &byte in &bytes[0..6] {
+            millis = (millis << 8) | u64::from(byte);
+        }
+
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_round_trips_through_a_v7_id() {
+        let expected = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let ctx = uuid::timestamp::context::NoContext;
+        let ts = uuid::Timestamp::from_unix(ctx, 1_700_000_000, 123_000_000);
+        let uuid = Uuid::new_v7(ts);
+
+        let got = Uuid::log_id_timestamp(&uuid.to_log_id()).expect("v7 id has a timestamp");
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn rejects_non_v7_uuids() {
+        let v4 = Uuid::new_v4();
+        assert!(Uuid::log_id_timestamp(&v4.to_log_id()).is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_input_like_from_log_id() {
+        assert!(Uuid::log_id_timestamp("too-short").is_none());
+        assert!(Uuid::log_id_timestamp("zz000000000000000000000000000000").is_none());
+    }
 }